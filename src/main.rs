@@ -3,7 +3,7 @@ fn main() {
 }
 
 // TODO: [ ] Create the webserver
-// TODO: [ ] Run system checks
+// TODO: [ ] Run system checks before binding (DB connectivity, migration status, frontend present, port available, upload dir writable), print a pass/fail report and fail fast with actionable messages on fatal issues
 // TODO: [ ] Create the UI for the website to be projected
 // TODO: [ ] Create the UI for the website students will see
 // TODO: [ ] Create .csv file for use with Excel
@@ -12,4 +12,102 @@ fn main() {
 // TODO: [ ] Create automated github workflow for building OS specific packages and adding them to a release page
 // TODO: [ ] Make a custom icon to show on the OS when the application is built
 // TODO: [ ] Error handling for when a user's device has already been used to mark attendance
-// TODO: [ ] Implement a way to retain a list of user devices that have already marked a student as attending. Clear the list on application exit and application start
+// TODO: [ ] Implement a way to retain a list of user devices that have already marked a student as attending. Clear the list on application exit and application start. Track devices by IP by default, optionally combined with a client-supplied device id (X-Device-Id) per course
+// TODO: [ ] Add a host-only endpoint to clone a course (settings, sections, logo) under a new name with zeroed attendance
+// TODO: [ ] Reject course updates made against a stale `updated_at` and return the current server state
+// TODO: [ ] Add per-course random jitter to confirmation-code rotation so all courses don't regenerate on the same tick
+// TODO: [ ] Add an explicit, env-var-gated demo mode that skips duplicate and code checks and warns loudly at startup
+// TODO: [ ] Return validation errors with per-field names so the UI can highlight the failing input
+// TODO: [ ] Add an endpoint describing the attendance form constraints (required fields, ID format, code length)
+// TODO: [ ] Allow several logos and a colour theme per course
+// TODO: [ ] Add bulk deletion of a course's attendance for a given day
+// TODO: [ ] Add a separate host-only audit export with IP and device id columns, kept apart from the student-facing export and gated behind an explicit acknowledgment parameter
+// TODO: [ ] Store the submitting IP address on each attendance record
+// TODO: [ ] Add a configurable minimum time between a student's consecutive submissions
+// TODO: [ ] Enforce one attendance per student per course per day with a unique index so concurrent submissions can't double-mark
+// TODO: [ ] Add a host-only endpoint to edit an existing attendance record
+// TODO: [ ] Normalize student names (trim, collapse internal whitespace, optional config-controlled title-casing) keeping accented and hyphenated names intact
+// TODO: [ ] Validate student ID format server-side using a per-course pattern
+// TODO: [ ] Add a configurable IP allowlist for the public API
+// TODO: [ ] Match CIDR ranges by prefix length for IPv4 and IPv6, not by string prefix
+// TODO: [ ] Add a configurable default set of sections for new courses
+// TODO: [ ] Add an endpoint returning a course's present count bucketed by hour
+// TODO: [ ] Retry binding the server a configurable number of times when the port is in use
+// TODO: [ ] Fall back to scanning for a free port when the configured one is taken
+// TODO: [ ] Serve the API under a version prefix and send deprecation headers on old routes
+// TODO: [ ] Batch and compress WebSocket updates for large rooms
+// TODO: [ ] Add a configurable cap on WebSocket connections per course
+// TODO: [ ] Let WebSocket clients resume their session after a reconnect
+// TODO: [ ] Add an endpoint listing all courses with their present counts in one call
+// TODO: [ ] Add a separate host-only absentees CSV export for a given date (roster minus that day's attendance), returning 409 when no roster exists
+// TODO: [ ] Compute the attendance date at insert time in a configurable timezone
+// TODO: [ ] Make the date format used in exports configurable per locale
+// TODO: [ ] Add a host-only endpoint to view and clear a course's current confirmation code
+// TODO: [ ] Allow more than one course to be active at the same time
+// TODO: [ ] Make the displayed confirmation code's padding and grouping configurable
+// TODO: [ ] Trim, strip spaces/hyphens and uppercase submitted confirmation codes before comparing
+// TODO: [ ] Make the attendance confirmation message and branding configurable
+// TODO: [ ] Include last-N-days attendance counts in the course response for a sparkline
+// TODO: [ ] Add an endpoint splitting today's attendees into new vs. returning students
+// TODO: [ ] When the current course is deleted, also clear the active host course and store the current-course preference as NULL/absent rather than ''
+// TODO: [ ] Fall back to the first existing course (and update the preference) when the saved current course was deleted
+// TODO: [ ] Add an endpoint that switches to and activates a course atomically
+// TODO: [ ] Add a configurable maximum number of courses
+// TODO: [ ] Add a search/filter endpoint for attendance records
+// TODO: [ ] Make opening the browser on startup configurable
+// TODO: [ ] When the browser fails to open, print the localhost and LAN URLs and a terminal QR of the LAN URL
+// TODO: [ ] Add an endpoint rendering the course attendance URL as a terminal-printable QR code
+// TODO: [ ] Make the SQLite connection pool size configurable
+// TODO: [ ] Retry transient DB errors (busy/locked) with backoff
+// TODO: [ ] Add a host-only endpoint to clear a student's record and device entry for today so they can resubmit
+// TODO: [ ] Let the user pick which columns go into CSV exports
+// TODO: [ ] Stream large CSV exports instead of buffering them in memory
+// TODO: [ ] Make the default logo path configurable
+// TODO: [ ] Add a settings table and a generic preferences API
+// TODO: [ ] Reload non-structural settings without restarting
+// TODO: [ ] Add a toggle so the host confirmation-code endpoint can return only expiry/progress metadata and leave delivery of the code to WebSocket push
+// TODO: [ ] Only trust X-Forwarded-For from configured proxies
+// TODO: [ ] Make the set of addresses treated as localhost for host-only routes configurable
+// TODO: [ ] Add an endpoint to test geofence settings before enabling them
+// TODO: [ ] Store a "current location" captured from the host browser for geofence setup
+// TODO: [ ] Detect local network ranges for IPv6 as well as IPv4
+// TODO: [ ] Version the attendance submission payload schema
+// TODO: [ ] Add an endpoint listing all sessions with their attendance counts
+// TODO: [ ] Allow reopening a closed session
+// TODO: [ ] Broadcast an attendance summary over WebSocket when a session closes
+// TODO: [ ] Make the student name field optional per configuration
+// TODO: [ ] Add an endpoint returning the QR code together with the live confirmation code
+// TODO: [ ] Make the QR payload configurable (full URL vs. raw token)
+// TODO: [ ] Add an endpoint to rotate confirmation codes for all courses at once
+// TODO: [ ] Return JSON 404s for unknown /api routes instead of the SPA page (configurable)
+// TODO: [ ] Serve uploads without directory listing unless explicitly enabled
+// TODO: [ ] Send correct content-type and cache headers for served uploads
+// TODO: [ ] Make the uploads directory configurable and separate from the frontend build
+// TODO: [ ] Convert uploaded logos to WebP
+// TODO: [ ] Add a maintenance-mode flag that pauses all check-ins
+// TODO: [ ] Make the JWT algorithm configurable and support key rotation
+// TODO: [ ] Add account lockout after failed logins and admin user management endpoints
+// TODO: [ ] Enforce password strength on registration and password change
+// TODO: [ ] Flag confirmation-code submissions from an IP subnet that differs from the majority and surface them in the audit view
+// TODO: [ ] Add an endpoint returning attendance as a students x dates matrix
+// TODO: [ ] Add a per-course gradebook CSV export in Canvas and generic LMS layouts with a configurable points scale
+// TODO: [ ] Distinguish "course not found" from "course not active" in responses (configurable)
+// TODO: [ ] Accept codes that expired within a small configurable clock-skew grace
+// TODO: [ ] Add an endpoint returning a course's confirmation code history
+// TODO: [ ] Import attendance in bulk from an external CSV
+// TODO: [ ] Make response compression configurable
+// TODO: [ ] Run a startup self-test that writes and reads back a test record
+// TODO: [ ] Configure CORS so credentials are never combined with a wildcard origin
+// TODO: [ ] Add an endpoint to check a confirmation code without recording attendance
+// TODO: [ ] Generate a printable attendance sheet as a paper fallback
+// TODO: [ ] Make default session names configurable
+// TODO: [ ] Add an endpoint returning course analytics for a dashboard
+// TODO: [ ] Compute the code-expiry progress from the configured code duration, clamped to 0-100
+// TODO: [ ] Define WebSocket messages as a versioned, typed enum
+// TODO: [ ] Keep a single attendance service layer shared by submission, statistics and export
+// TODO: [ ] Use one attendance table name across submission, statistics and export
+// TODO: [ ] Set attendance_date on every insert so same-day duplicate checks and counts match
+// TODO: [ ] Add an integration test harness using an in-memory SQLite database
+// TODO: [ ] Cap the number of records a non-streamed export returns (configurable)
+// TODO: [ ] Allow a per-course fixed confirmation code that never rotates
+// TODO: [ ] Add an endpoint returning a course's total, present and remaining capacity for today